@@ -0,0 +1,238 @@
+use super::Suggestion;
+use crate::ast::Span;
+
+/// A stable, machine-readable identifier for a diagnostic kind.
+///
+/// Codes are permanent: once assigned to a variant they must not be reused for a different
+/// kind of problem, so editor/LSP tooling can key quick-fixes, suppressions and documentation
+/// links off the code alone, without parsing the human-readable message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DiagnosticCode(pub &'static str);
+
+impl std::fmt::Display for DiagnosticCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The kind of problem a [`DatamodelError`] reports, and the data needed to render it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DatamodelErrorKind {
+    ArgumentNotFound {
+        argument_name: String,
+        span: Span,
+    },
+
+    AttributeValidationError {
+        message: String,
+        attribute_name: String,
+        span: Span,
+    },
+
+    ModelValidationError {
+        message: String,
+        model_name: String,
+        span: Span,
+    },
+
+    FieldValidationError {
+        message: String,
+        model_name: String,
+        field_name: String,
+        span: Span,
+    },
+
+    EnumValidationError {
+        message: String,
+        enum_name: String,
+        span: Span,
+    },
+
+    ConnectorError {
+        message: String,
+        span: Span,
+    },
+
+    ValidationError {
+        message: String,
+        span: Span,
+    },
+
+    MultipleIndexesWithSameNameAreNotSupported {
+        index_name: String,
+        span: Span,
+    },
+
+    ScalarListFieldsAreNotSupported {
+        model_name: String,
+        field_name: String,
+        span: Span,
+    },
+}
+
+/// An error that occurred during schema validation.
+///
+/// Carries a [`DiagnosticCode`] (see [`DatamodelError::code`]) in addition to its human-readable
+/// message, so that downstream consumers can filter, suppress or link documentation by code
+/// while the rendered text stays backwards compatible. `suggestions` holds zero or more
+/// structured, machine-applicable fixes (see [`Suggestion`]) that a formatter or LSP can offer
+/// as one-click quick-fixes instead of only printing prose.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DatamodelError {
+    kind: DatamodelErrorKind,
+    suggestions: Vec<Suggestion>,
+}
+
+impl DatamodelError {
+    fn new(kind: DatamodelErrorKind) -> DatamodelError {
+        DatamodelError {
+            kind,
+            suggestions: Vec::new(),
+        }
+    }
+
+    /// Attaches structured quick-fix suggestions to this error. Returns `self` so call sites can
+    /// build the error and its suggestions in one expression.
+    pub fn with_suggestions(mut self, suggestions: Vec<Suggestion>) -> DatamodelError {
+        self.suggestions = suggestions;
+        self
+    }
+
+    /// Convenience for the common case of a single suggestion.
+    pub fn with_suggestion(self, suggestion: Suggestion) -> DatamodelError {
+        self.with_suggestions(vec![suggestion])
+    }
+
+    pub fn new_argument_not_found_error(argument_name: &str, span: Span) -> DatamodelError {
+        DatamodelError::new(DatamodelErrorKind::ArgumentNotFound {
+            argument_name: argument_name.to_owned(),
+            span,
+        })
+    }
+
+    pub fn new_attribute_validation_error(message: &str, attribute_name: &str, span: Span) -> DatamodelError {
+        DatamodelError::new(DatamodelErrorKind::AttributeValidationError {
+            message: message.to_owned(),
+            attribute_name: attribute_name.to_owned(),
+            span,
+        })
+    }
+
+    pub fn new_model_validation_error(message: &str, model_name: &str, span: Span) -> DatamodelError {
+        DatamodelError::new(DatamodelErrorKind::ModelValidationError {
+            message: message.to_owned(),
+            model_name: model_name.to_owned(),
+            span,
+        })
+    }
+
+    pub fn new_field_validation_error(message: &str, model_name: &str, field_name: &str, span: Span) -> DatamodelError {
+        DatamodelError::new(DatamodelErrorKind::FieldValidationError {
+            message: message.to_owned(),
+            model_name: model_name.to_owned(),
+            field_name: field_name.to_owned(),
+            span,
+        })
+    }
+
+    pub fn new_enum_validation_error(message: &str, enum_name: &str, span: Span) -> DatamodelError {
+        DatamodelError::new(DatamodelErrorKind::EnumValidationError {
+            message: message.to_owned(),
+            enum_name: enum_name.to_owned(),
+            span,
+        })
+    }
+
+    pub fn new_connector_error(message: &str, span: Span) -> DatamodelError {
+        DatamodelError::new(DatamodelErrorKind::ConnectorError {
+            message: message.to_owned(),
+            span,
+        })
+    }
+
+    pub fn new_validation_error(message: &str, span: Span) -> DatamodelError {
+        DatamodelError::new(DatamodelErrorKind::ValidationError {
+            message: message.to_owned(),
+            span,
+        })
+    }
+
+    pub fn new_multiple_indexes_with_same_name_are_not_supported(index_name: &str, span: Span) -> DatamodelError {
+        DatamodelError::new(DatamodelErrorKind::MultipleIndexesWithSameNameAreNotSupported {
+            index_name: index_name.to_owned(),
+            span,
+        })
+    }
+
+    pub fn new_scalar_list_fields_are_not_supported(model_name: &str, field_name: &str, span: Span) -> DatamodelError {
+        DatamodelError::new(DatamodelErrorKind::ScalarListFieldsAreNotSupported {
+            model_name: model_name.to_owned(),
+            field_name: field_name.to_owned(),
+            span,
+        })
+    }
+
+    /// The permanent, stable code for this diagnostic's kind. Safe to use for filtering,
+    /// suppression and documentation links (`https://pris.ly/d/<code>`).
+    pub fn code(&self) -> DiagnosticCode {
+        match &self.kind {
+            DatamodelErrorKind::ArgumentNotFound { .. } => DiagnosticCode("P1010"),
+            DatamodelErrorKind::AttributeValidationError { .. } => DiagnosticCode("P1011"),
+            DatamodelErrorKind::ModelValidationError { .. } => DiagnosticCode("P1012"),
+            DatamodelErrorKind::FieldValidationError { .. } => DiagnosticCode("P1013"),
+            DatamodelErrorKind::EnumValidationError { .. } => DiagnosticCode("P1014"),
+            DatamodelErrorKind::ConnectorError { .. } => DiagnosticCode("P1015"),
+            DatamodelErrorKind::ValidationError { .. } => DiagnosticCode("P1016"),
+            DatamodelErrorKind::MultipleIndexesWithSameNameAreNotSupported { .. } => DiagnosticCode("P1017"),
+            DatamodelErrorKind::ScalarListFieldsAreNotSupported { .. } => DiagnosticCode("P1018"),
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        match &self.kind {
+            DatamodelErrorKind::ArgumentNotFound { span, .. } => *span,
+            DatamodelErrorKind::AttributeValidationError { span, .. } => *span,
+            DatamodelErrorKind::ModelValidationError { span, .. } => *span,
+            DatamodelErrorKind::FieldValidationError { span, .. } => *span,
+            DatamodelErrorKind::EnumValidationError { span, .. } => *span,
+            DatamodelErrorKind::ConnectorError { span, .. } => *span,
+            DatamodelErrorKind::ValidationError { span, .. } => *span,
+            DatamodelErrorKind::MultipleIndexesWithSameNameAreNotSupported { span, .. } => *span,
+            DatamodelErrorKind::ScalarListFieldsAreNotSupported { span, .. } => *span,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match &self.kind {
+            DatamodelErrorKind::ArgumentNotFound { argument_name, .. } => {
+                format!("Argument \"{}\" is missing.", argument_name)
+            }
+            DatamodelErrorKind::AttributeValidationError { message, .. } => message.clone(),
+            DatamodelErrorKind::ModelValidationError { message, .. } => message.clone(),
+            DatamodelErrorKind::FieldValidationError { message, .. } => message.clone(),
+            DatamodelErrorKind::EnumValidationError { message, .. } => message.clone(),
+            DatamodelErrorKind::ConnectorError { message, .. } => message.clone(),
+            DatamodelErrorKind::ValidationError { message, .. } => message.clone(),
+            DatamodelErrorKind::MultipleIndexesWithSameNameAreNotSupported { index_name, .. } => {
+                format!("The index name `{}` is already in use.", index_name)
+            }
+            DatamodelErrorKind::ScalarListFieldsAreNotSupported {
+                model_name, field_name, ..
+            } => format!(
+                "Field `{}` in model `{}` can't be a list. The current connector does not support lists of primitive types.",
+                field_name, model_name
+            ),
+        }
+    }
+
+    /// Structured, machine-applicable fixes for this error, if any were attached.
+    pub fn suggestions(&self) -> &[Suggestion] {
+        &self.suggestions
+    }
+}
+
+impl std::fmt::Display for DatamodelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}", self.code(), self.message())
+    }
+}
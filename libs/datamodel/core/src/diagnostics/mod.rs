@@ -0,0 +1,76 @@
+//! Diagnostics emitted while parsing and validating a datamodel: errors, and (see
+//! [`DatamodelWarning`]) non-fatal warnings.
+
+mod error;
+mod suggestion;
+mod warning;
+
+pub use error::{DatamodelError, DiagnosticCode};
+pub use suggestion::{Applicability, Suggestion};
+pub use warning::DatamodelWarning;
+
+/// A collection of errors and warnings accumulated while validating a datamodel. Validations
+/// push into a `Diagnostics` rather than failing fast, so a user fixing their schema sees every
+/// problem at once instead of one error per run. Warnings never turn a successful validation
+/// into a failing one; only `errors` gate [`Diagnostics::to_result`].
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Diagnostics {
+    errors: Vec<DatamodelError>,
+    warnings: Vec<DatamodelWarning>,
+}
+
+impl Diagnostics {
+    pub fn new() -> Diagnostics {
+        Diagnostics {
+            errors: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    pub fn push_error(&mut self, error: DatamodelError) {
+        self.errors.push(error);
+    }
+
+    pub fn push_opt_error(&mut self, error: Option<DatamodelError>) {
+        if let Some(error) = error {
+            self.push_error(error);
+        }
+    }
+
+    pub fn append_error_vec(&mut self, errors: Vec<DatamodelError>) {
+        self.errors.extend(errors);
+    }
+
+    pub fn push_warning(&mut self, warning: DatamodelWarning) {
+        self.warnings.push(warning);
+    }
+
+    pub fn append(&mut self, other: &mut Diagnostics) {
+        self.errors.append(&mut other.errors);
+        self.warnings.append(&mut other.warnings);
+    }
+
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+
+    pub fn has_warnings(&self) -> bool {
+        !self.warnings.is_empty()
+    }
+
+    pub fn errors(&self) -> &[DatamodelError] {
+        &self.errors
+    }
+
+    pub fn warnings(&self) -> &[DatamodelWarning] {
+        &self.warnings
+    }
+
+    pub fn to_result(self) -> Result<(), Diagnostics> {
+        if self.has_errors() {
+            Err(self)
+        } else {
+            Ok(())
+        }
+    }
+}
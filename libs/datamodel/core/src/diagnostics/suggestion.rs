@@ -0,0 +1,35 @@
+use crate::ast::Span;
+
+/// How safe a [`Suggestion`] is to apply without human review, mirroring rustc's
+/// `Applicability` levels for `span_suggestion`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Applicability {
+    /// The suggestion is definitely what the user intended. Safe to apply automatically.
+    MachineApplicable,
+    /// The suggestion is probably correct, but the user should review it before applying.
+    MaybeIncorrect,
+    /// The suggestion contains placeholders the user must fill in before it can be applied.
+    HasPlaceholders,
+}
+
+/// A structured, machine-applicable fix for a [`super::DatamodelError`]: replace the text at
+/// `span` with `replacement`. The formatter and LSP integrations use these to offer one-click
+/// fixes instead of only printing prose.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    pub span: Span,
+    pub replacement: String,
+    pub label: String,
+    pub applicability: Applicability,
+}
+
+impl Suggestion {
+    pub fn new(label: &str, span: Span, replacement: &str, applicability: Applicability) -> Suggestion {
+        Suggestion {
+            span,
+            replacement: replacement.to_owned(),
+            label: label.to_owned(),
+            applicability,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::ast::Span;
+
+/// A non-fatal problem found while validating a datamodel. Unlike [`super::DatamodelError`],
+/// a warning does not prevent the schema from being used; it flags something that is probably
+/// not what the user intended.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DatamodelWarning {
+    message: String,
+    span: Span,
+}
+
+impl DatamodelWarning {
+    pub fn new(message: String, span: Span) -> DatamodelWarning {
+        DatamodelWarning { message, span }
+    }
+
+    pub fn new_redundant_unique_criteria_warning(model_name: &str, redundant: &[String], implied_by: &[String], span: Span) -> DatamodelWarning {
+        DatamodelWarning::new(
+            format!(
+                "The unique criteria `{}` on model `{}` is redundant: it is already implied by the unique criteria `{}`. Consider removing it.",
+                redundant.join(", "),
+                model_name,
+                implied_by.join(", "),
+            ),
+            span,
+        )
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl std::fmt::Display for DatamodelWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
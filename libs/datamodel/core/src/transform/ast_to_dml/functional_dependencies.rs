@@ -0,0 +1,140 @@
+use crate::dml;
+use std::collections::BTreeSet;
+
+/// A functional dependency `lhs -> rhs`: the fields in `lhs` determine the fields in `rhs`.
+/// Built from a model's declared candidate keys (`@id`/`@unique` and `@@id`/`@@unique`), where
+/// every key functionally determines every other field.
+struct FunctionalDependency {
+    lhs: BTreeSet<String>,
+    rhs: BTreeSet<String>,
+}
+
+/// The set of functional dependencies implied by a model's declared candidate keys, used to
+/// compute attribute closures and detect redundant unique criteria.
+pub struct FdSet {
+    all_fields: BTreeSet<String>,
+    dependencies: Vec<FunctionalDependency>,
+}
+
+impl FdSet {
+    /// Builds the FD set for a model: every candidate key (`@id`/`@unique` field, or `@@id`/
+    /// `@@unique` field set) functionally determines every other field on the model.
+    pub fn from_model(model: &dml::Model) -> FdSet {
+        let all_fields: BTreeSet<String> = model.fields.iter().map(|f| f.name().to_owned()).collect();
+
+        let mut dependencies = Vec::new();
+        for criteria in model.loose_unique_criterias() {
+            let lhs: BTreeSet<String> = criteria.fields.iter().map(|f| f.name.clone()).collect();
+            let rhs = all_fields.difference(&lhs).cloned().collect();
+            dependencies.push(FunctionalDependency { lhs, rhs });
+        }
+
+        FdSet { all_fields, dependencies }
+    }
+
+    /// The attribute closure of `attrs` under a subset of the FD set: starting from `attrs`,
+    /// repeatedly add the right-hand side of any dependency (other than the one at `excluding`,
+    /// if given) whose left-hand side is already covered, until no further field can be added.
+    ///
+    /// Every declared candidate key is, by construction, its own superkey (its FD is
+    /// `lhs -> all other fields`), so computing a criterion's closure against the *full*
+    /// dependency set (including its own FD) always yields every field, trivially. That makes
+    /// the closure useless for judging whether a criterion is redundant: excluding its own FD is
+    /// what lets us ask whether the model's *other* declared keys already imply it.
+    fn closure_excluding(&self, excluding: Option<usize>, attrs: &BTreeSet<String>) -> BTreeSet<String> {
+        let mut closure = attrs.clone();
+
+        loop {
+            let mut changed = false;
+
+            for (idx, dep) in self.dependencies.iter().enumerate() {
+                if Some(idx) == excluding {
+                    continue;
+                }
+
+                if dep.lhs.is_subset(&closure) && !dep.rhs.is_subset(&closure) {
+                    closure.extend(dep.rhs.iter().cloned());
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        closure
+    }
+
+    /// The attribute closure of `attrs`: starting from `attrs`, repeatedly add the right-hand
+    /// side of any functional dependency whose left-hand side is already covered, until no
+    /// further field can be added.
+    pub fn closure(&self, attrs: &BTreeSet<String>) -> BTreeSet<String> {
+        self.closure_excluding(None, attrs)
+    }
+
+    /// A field set is a superkey iff its closure contains every field on the model.
+    pub fn is_superkey(&self, attrs: &BTreeSet<String>) -> bool {
+        self.closure(attrs) == self.all_fields
+    }
+}
+
+/// A declared unique criterion found to be redundant: it contains every field of another
+/// declared criterion (a strict superset, or an identical duplicate), so the extra fields add
+/// nothing. `implied_by` is the smallest such contained criterion.
+pub struct RedundantCriteria {
+    pub redundant: Vec<String>,
+    pub implied_by: Vec<String>,
+}
+
+/// Finds declared unique criteria (`@@id`/`@@unique`, and single-field `@id`/`@unique`) that are
+/// logically redundant given the model's other declared keys: a criterion whose fields are
+/// already fully determined by the model's *other* candidate keys, so its own declaration adds
+/// no information.
+///
+/// Every criterion trivially determines the whole row by itself (that's what makes it a
+/// candidate key), so a criterion is only reported here when it is implied *without* relying on
+/// that triviality — i.e. its closure still reaches every field once its own functional
+/// dependency is excluded from the set.
+pub fn find_redundant_unique_criteria(model: &dml::Model) -> Vec<RedundantCriteria> {
+    let fd_set = FdSet::from_model(model);
+    let criterias: Vec<BTreeSet<String>> = model
+        .loose_unique_criterias()
+        .iter()
+        .map(|criteria| criteria.fields.iter().map(|f| f.name.clone()).collect())
+        .collect();
+
+    // A criterion can only be redundant if some *other* declared criterion implies it; with
+    // fewer than two criteria there's nothing else to blame it on (and, e.g. a model with a
+    // single `@id` spanning every field would otherwise trivially satisfy the closure check
+    // below and have no other criterion left to attribute the redundancy to).
+    if criterias.len() < 2 {
+        return Vec::new();
+    }
+
+    let mut redundant = Vec::new();
+
+    for (i, a) in criterias.iter().enumerate() {
+        if fd_set.closure_excluding(Some(i), a) != fd_set.all_fields {
+            continue;
+        }
+
+        // `a` is redundant; blame the smallest other criterion contained in it for the
+        // diagnostic message. One always exists: without its own FD, `a`'s closure can only
+        // grow if another criterion's fields are a subset of `a`.
+        let implied_by = criterias
+            .iter()
+            .enumerate()
+            .filter(|(j, b)| *j != i && b.is_subset(a))
+            .min_by_key(|(j, b)| (b.len(), *j))
+            .map(|(_, b)| b)
+            .expect("a criterion found redundant always has another declared criterion to blame");
+
+        redundant.push(RedundantCriteria {
+            redundant: a.iter().cloned().collect(),
+            implied_by: implied_by.iter().cloned().collect(),
+        });
+    }
+
+    redundant
+}
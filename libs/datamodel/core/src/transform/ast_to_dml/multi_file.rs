@@ -0,0 +1,136 @@
+use crate::ast;
+use crate::diagnostics::{DatamodelError, Diagnostics};
+use std::collections::HashMap;
+
+/// One `.prisma` file taking part in a multi-file resolution pass, identified by a stable name
+/// (usually its path) so diagnostics can point back at the right file.
+pub struct SchemaFile<'a> {
+    pub name: &'a str,
+    pub ast: &'a ast::SchemaAst,
+}
+
+impl<'a> SchemaFile<'a> {
+    pub fn new(name: &'a str, ast: &'a ast::SchemaAst) -> SchemaFile<'a> {
+        SchemaFile { name, ast }
+    }
+}
+
+/// A top-level declaration (model or enum) together with the file it was declared in, so
+/// cross-file relation resolution can report precisely where a referenced type lives.
+#[derive(Debug, Clone, Copy)]
+pub struct ResolvedDeclaration<'a> {
+    pub file_name: &'a str,
+    pub span: ast::Span,
+}
+
+/// The result of merging a set of named ASTs into a single symbol table: every model and enum
+/// name maps to the file that declares it. Built by [`resolve_schemas`].
+pub struct MergedSchema<'a> {
+    declarations: HashMap<String, ResolvedDeclaration<'a>>,
+}
+
+impl<'a> MergedSchema<'a> {
+    /// Looks up a model or enum name across every file that took part in the merge, regardless
+    /// of which file the lookup originates from. This is what lets a relation in one file point
+    /// at a model defined in another.
+    pub fn find(&self, name: &str) -> Option<&ResolvedDeclaration<'a>> {
+        self.declarations.get(name)
+    }
+
+    pub fn file_of(&self, name: &str) -> Option<&'a str> {
+        self.find(name).map(|decl| decl.file_name)
+    }
+}
+
+/// Resolves a set of named `.prisma` files into a single merged symbol table, so models and
+/// enums referenced across separate files can be validated together.
+///
+/// Returns the merged symbol table on success, or a [`Diagnostics`] describing every duplicate
+/// declaration and unresolved cross-file reference found. This is a pre-check, run by
+/// [`super::validate::Validator::validate_files`] before the individual ASTs are merged into the
+/// single `ast::SchemaAst` / `dml::Datamodel` that `Validator::validate` validates today: it
+/// catches name collisions and dangling type references across files up front, with diagnostics
+/// that can point at the specific file each half of the problem came from, before the merge
+/// loses that distinction.
+///
+/// This crate has no cross-file lowering step yet - `Validator::validate_files` still takes an
+/// already-merged `ast::SchemaAst`/`dml::Datamodel` alongside `schemas`, so
+/// `validate_base_fields_for_relation`/`validate_referenced_fields_for_relation` only ever see
+/// that single merged datamodel, never `schemas` individually. Until that lowering step exists,
+/// `resolve_schemas` only buys an earlier, file-attributed error for the collisions and
+/// unresolved references it itself checks for, not full cross-file relation validation.
+pub fn resolve_schemas<'a>(schemas: &[SchemaFile<'a>]) -> Result<MergedSchema<'a>, Diagnostics> {
+    let mut diagnostics = Diagnostics::new();
+    let mut declarations: HashMap<String, ResolvedDeclaration<'a>> = HashMap::new();
+
+    for schema in schemas {
+        for model in schema.ast.models() {
+            declare(
+                &mut declarations,
+                &mut diagnostics,
+                schema.name,
+                &model.name.name,
+                model.span,
+            );
+        }
+
+        for enum_decl in schema.ast.enums() {
+            declare(
+                &mut declarations,
+                &mut diagnostics,
+                schema.name,
+                &enum_decl.name.name,
+                enum_decl.span,
+            );
+        }
+    }
+
+    for schema in schemas {
+        for model in schema.ast.models() {
+            for field in model.fields.iter() {
+                if let ast::FieldType::Base(type_name, _) = &field.field_type {
+                    if declarations.get(type_name).is_none() && !is_builtin_scalar(type_name) {
+                        diagnostics.push_error(DatamodelError::new_field_validation_error(
+                            &format!(
+                                "Type `{}` is neither a built-in type, nor refers to another model, composite type, or enum declared in `{}` or any of the schemas it resolves against.",
+                                type_name, schema.name
+                            ),
+                            &model.name.name,
+                            &field.name.name,
+                            field.span,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    diagnostics.to_result().map(|_| MergedSchema { declarations })
+}
+
+fn declare<'a>(
+    declarations: &mut HashMap<String, ResolvedDeclaration<'a>>,
+    diagnostics: &mut Diagnostics,
+    file_name: &'a str,
+    name: &str,
+    span: ast::Span,
+) {
+    if let Some(existing) = declarations.get(name) {
+        diagnostics.push_error(DatamodelError::new_validation_error(
+            &format!(
+                "The name `{}` is declared more than once: once in `{}`, and once in `{}`. Names must be unique across every schema file that is resolved together.",
+                name, existing.file_name, file_name
+            ),
+            span,
+        ));
+    } else {
+        declarations.insert(name.to_owned(), ResolvedDeclaration { file_name, span });
+    }
+}
+
+fn is_builtin_scalar(name: &str) -> bool {
+    matches!(
+        name,
+        "String" | "Boolean" | "Int" | "BigInt" | "Float" | "Decimal" | "DateTime" | "Json" | "Bytes"
+    )
+}
@@ -0,0 +1,15 @@
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml};
+
+/// Resolves a relation's `to` target against every model in the datamodel, turning an unknown
+/// target into a proper validation error instead of the `find_model(...).expect(STATE_ERROR)`
+/// panic this replaces.
+pub fn resolve_relation_target<'a>(
+    datamodel: &'a dml::Datamodel,
+    to: &str,
+    span: ast::Span,
+) -> Result<&'a dml::Model, DatamodelError> {
+    datamodel.models().find(|model| model.name == to).ok_or_else(|| {
+        DatamodelError::new_validation_error(&format!("Type `{}` is neither a built-in type nor a model.", to), span)
+    })
+}
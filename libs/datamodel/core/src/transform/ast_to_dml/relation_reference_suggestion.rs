@@ -0,0 +1,76 @@
+use crate::ast::Span;
+use crate::diagnostics::{Applicability, Suggestion};
+use crate::dml;
+
+/// Finds the declared unique criteria on `related_model` that overlaps the most with
+/// `references`, and renders a concrete fix: which fields to add or remove so `references`
+/// matches it, and, when `strict_relation_field_order` is set, the exact reordering needed so
+/// `references` lines up with the criteria's own field order.
+///
+/// Returns `None` if the related model declares no unique criteria at all, in which case there
+/// is nothing closer to suggest.
+pub fn suggest_closest_unique_criteria(
+    references: &[String],
+    related_model: &dml::Model,
+    strict_relation_field_order: bool,
+    span: Span,
+) -> Option<Suggestion> {
+    let criterias = related_model.loose_unique_criterias();
+
+    let closest = criterias.iter().max_by_key(|criteria| {
+        let criteria_fields: Vec<&str> = criteria.fields.iter().map(|f| f.name.as_str()).collect();
+        let overlap = references.iter().filter(|r| criteria_fields.contains(&r.as_str())).count();
+        // Prefer the criteria with the most overlap, and among ties the smallest one (fewest
+        // extra fields to add).
+        (overlap as isize, -(criteria_fields.len() as isize))
+    })?;
+
+    let criteria_fields: Vec<String> = closest.fields.iter().map(|f| f.name.clone()).collect();
+
+    let ordered_correctly = !strict_relation_field_order
+        || (references.len() == criteria_fields.len() && references.iter().eq(criteria_fields.iter()));
+
+    if references == &criteria_fields[..] && ordered_correctly {
+        return None;
+    }
+
+    let label = if references.iter().collect::<std::collections::HashSet<_>>()
+        == criteria_fields.iter().collect::<std::collections::HashSet<_>>()
+    {
+        format!(
+            "Reorder `references` to match the unique criteria's field order: [{}]",
+            criteria_fields.join(", ")
+        )
+    } else {
+        let to_add: Vec<&String> = criteria_fields.iter().filter(|f| !references.contains(f)).collect();
+        let to_remove: Vec<&String> = references.iter().filter(|f| !criteria_fields.contains(f)).collect();
+
+        let mut parts = Vec::new();
+        if !to_add.is_empty() {
+            parts.push(format!(
+                "add {}",
+                to_add.iter().map(|f| format!("`{}`", f)).collect::<Vec<_>>().join(", ")
+            ));
+        }
+        if !to_remove.is_empty() {
+            parts.push(format!(
+                "remove {}",
+                to_remove.iter().map(|f| format!("`{}`", f)).collect::<Vec<_>>().join(", ")
+            ));
+        }
+
+        format!(
+            "Change `references` to [{}] ({}) to match the unique criteria on `{}`",
+            criteria_fields.join(", "),
+            parts.join(" and "),
+            related_model.name
+        )
+    };
+
+    Some(Suggestion::new(
+        &label,
+        span,
+        &format!("[{}]", criteria_fields.join(", ")),
+        Applicability::MaybeIncorrect,
+    ))
+}
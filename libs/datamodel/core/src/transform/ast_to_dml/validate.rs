@@ -1,17 +1,33 @@
+use super::functional_dependencies;
+use super::multi_file;
+use super::qualified_model_resolution;
+use super::relation_reference_suggestion;
 use crate::ast::WithAttributes;
 use crate::{
     ast, configuration,
-    diagnostics::{DatamodelError, Diagnostics},
+    diagnostics::{Applicability, DatamodelError, DatamodelWarning, Diagnostics, Suggestion},
     dml, DefaultValue, FieldType,
 };
 use prisma_value::PrismaValue;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
+
+/// Whether ambiguous-relation-name problems should be reported as errors, or silently fixed by
+/// generating deterministic relation names instead. See [`Validator::in_fix_mode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ValidationMode {
+    Strict,
+    Fix,
+}
 
 /// Helper for validating a datamodel.
 ///
 /// When validating, we check if the datamodel is valid, and generate errors otherwise.
 pub struct Validator<'a> {
     source: Option<&'a configuration::Datasource>,
+    mode: ValidationMode,
+    /// Deterministic AST patches accumulated while validating in [`ValidationMode::Fix`]. See
+    /// [`Validator::in_fix_mode`] and [`Validator::take_relation_name_fixes`].
+    relation_name_fixes: std::cell::RefCell<Vec<Suggestion>>,
 }
 
 /// State error message. Seeing this error means something went really wrong internally. It's the datamodel equivalent of a bluescreen.
@@ -23,7 +39,51 @@ const PRISMA_FORMAT_HINT: &str = "You can run `prisma format` to fix this automa
 impl<'a> Validator<'a> {
     /// Creates a new instance, with all builtin attributes registered.
     pub fn new(source: Option<&'a configuration::Datasource>) -> Validator<'a> {
-        Self { source }
+        Self {
+            source,
+            mode: ValidationMode::Strict,
+            relation_name_fixes: std::cell::RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Drains and returns the relation-name patches generated while validating in
+    /// [`ValidationMode::Fix`]. Each patch is idempotent: re-running the validator against a
+    /// schema that already applied the previous patches produces no further patches, because
+    /// the generated names are derived deterministically from the sorted field names.
+    pub fn take_relation_name_fixes(&self) -> Vec<Suggestion> {
+        self.relation_name_fixes.borrow_mut().drain(..).collect()
+    }
+
+    /// Puts the validator in `prisma format --fix`-style non-fatal mode: disambiguation-class
+    /// problems (unnamed/ambiguous self relations and multi-field relations) are no longer
+    /// reported as errors. Instead, [`Validator::validate`] succeeds and the deterministic AST
+    /// patches needed to disambiguate them are available through
+    /// [`Validator::take_relation_name_fixes`]. This is meant for callers that repair a schema
+    /// after `prisma format` leaves `@relation(fields: …, references: …)` without a name.
+    pub fn in_fix_mode(mut self) -> Self {
+        self.mode = ValidationMode::Fix;
+        self
+    }
+
+    /// Entry point for validating a datamodel assembled out of more than one named `.prisma`
+    /// file: runs [`super::multi_file::resolve_schemas`] first, so duplicate declarations and
+    /// dangling type references across files are reported with the originating file identity,
+    /// before falling through to the single-schema [`Validator::validate`].
+    ///
+    /// `schema`/`ast_schema` must already be the result of merging every file in `schemas` into
+    /// one `ast::SchemaAst` / `dml::Datamodel` - this crate has no cross-file lowering step yet,
+    /// so that merge is the caller's responsibility. Until one exists, `validate_base_fields_for_relation`
+    /// and `validate_referenced_fields_for_relation` still only ever see the single merged
+    /// `dml::Datamodel`, not `schemas` individually; this only buys an earlier, better-attributed
+    /// error for the name-collision/unresolved-reference cases `resolve_schemas` itself covers.
+    pub fn validate_files(
+        &self,
+        schemas: &[multi_file::SchemaFile<'_>],
+        ast_schema: &ast::SchemaAst,
+        schema: &mut dml::Datamodel,
+    ) -> Result<(), Diagnostics> {
+        multi_file::resolve_schemas(schemas)?;
+        self.validate(ast_schema, schema)
     }
 
     pub fn validate(&self, ast_schema: &ast::SchemaAst, schema: &mut dml::Datamodel) -> Result<(), Diagnostics> {
@@ -44,22 +104,31 @@ impl<'a> Validator<'a> {
 
             if let Some(sf) = model.scalar_fields().find(|f| f.is_id && !f.is_required()) {
                 if !model.is_ignored {
-                    let span = ast_schema
+                    let ast_field = ast_schema
                         .models()
                         .iter()
                         .find(|ast_model| ast_model.name.name == model.name)
                         .unwrap()
                         .fields
                         .iter()
-                        .find(|f| f.name.name == sf.name)
+                        .find(|f| f.name.name == sf.name);
+
+                    let span = ast_field
                         .map(|f| f.attributes.iter().find(|att| att.name.name == "id").unwrap().span)
                         .unwrap_or_else(ast::Span::empty);
 
-                    all_errors.push_error(DatamodelError::new_attribute_validation_error(
+                    let error = DatamodelError::new_attribute_validation_error(
                         "Fields that are marked as id must be required.",
                         "id",
                         span,
-                    ));
+                    );
+
+                    // No suggestion is attached here: the only span available for this field is
+                    // its whole declaration (name, type, and attributes together), and a fix that
+                    // replaces all of that with a bare placeholder would delete the field's name
+                    // and attributes rather than just drop the `?`/`[]` modifier.
+
+                    all_errors.push_error(error);
                 }
             }
 
@@ -73,9 +142,7 @@ impl<'a> Validator<'a> {
                 errors_for_model.push_error(err);
             }
 
-            if let Err(err) = self.validate_relations_not_ambiguous(ast_schema, model) {
-                errors_for_model.push_error(err);
-            }
+            errors_for_model.append(&mut self.validate_relations_not_ambiguous(ast_schema, model));
 
             if let Err(ref mut the_errors) =
                 self.validate_field_arities(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
@@ -129,6 +196,15 @@ impl<'a> Validator<'a> {
                 errors_for_model.append(the_errors);
             }
 
+            for redundant in functional_dependencies::find_redundant_unique_criteria(model) {
+                errors_for_model.push_warning(DatamodelWarning::new_redundant_unique_criteria_warning(
+                    &model.name,
+                    &redundant.redundant,
+                    &redundant.implied_by,
+                    ast_schema.find_model(&model.name).expect(STATE_ERROR).span,
+                ));
+            }
+
             all_errors.append(&mut errors_for_model);
         }
 
@@ -142,6 +218,13 @@ impl<'a> Validator<'a> {
                 errors_for_enum.push_error(err);
             }
 
+            if let Err(ref mut the_errors) = self.validate_enum(
+                ast_schema.find_enum(&declared_enum.name).expect(STATE_ERROR),
+                declared_enum,
+            ) {
+                errors_for_enum.append(the_errors);
+            }
+
             all_errors.append(&mut errors_for_enum);
         }
 
@@ -159,6 +242,10 @@ impl<'a> Validator<'a> {
     ) -> Result<(), Diagnostics> {
         let mut all_errors = Diagnostics::new();
 
+        self.infer_one_to_one_relation_sides(schema);
+
+        all_errors.append(&mut self.validate_relation_names_globally_consistent(ast_schema, schema));
+
         // Model level validations.
         for model in schema.models() {
             // Having a separate error collection allows checking whether any error has occurred for a model.
@@ -173,6 +260,9 @@ impl<'a> Validator<'a> {
                 errors_for_model.append(&mut new_errors);
             }
 
+            errors_for_model.append(&mut self.validate_through_relations(ast_schema, schema, model));
+            errors_for_model.append(&mut self.validate_relation_defaults(ast_schema, schema, model));
+
             all_errors.append(&mut errors_for_model);
         }
 
@@ -233,10 +323,29 @@ impl<'a> Validator<'a> {
                                 .find(|attribute| attribute.is_index())
                                 .unwrap();
 
-                            errors.push_error(DatamodelError::new_multiple_indexes_with_same_name_are_not_supported(
+                            let mut error = DatamodelError::new_multiple_indexes_with_same_name_are_not_supported(
                                 index_name,
                                 ast_index.span,
-                            ));
+                            );
+
+                            // Narrow the fix to just the `name` argument's span: replacing the
+                            // whole `@@index(...)`/`@@unique(...)` attribute with a bare string
+                            // would not even be valid attribute syntax.
+                            if let Some(name_arg) = ast_index
+                                .arguments
+                                .arguments
+                                .iter()
+                                .find(|arg| arg.name.as_ref().map(|name| name.name.as_str()) == Some("name"))
+                            {
+                                error = error.with_suggestion(Suggestion::new(
+                                    "Give this index a distinct name",
+                                    name_arg.span,
+                                    &format!("name: \"{}_2\"", index_name),
+                                    Applicability::MaybeIncorrect,
+                                ));
+                            }
+
+                            errors.push_error(error);
                         }
                         index_names.insert(index_name);
                     }
@@ -452,7 +561,13 @@ impl<'a> Validator<'a> {
                 ),
                 &model.name,
                 ast_model.span,
-            ))
+            )
+            .with_suggestion(Suggestion::new(
+                "Rename the model to a non-reserved name",
+                ast_model.name.span,
+                &format!("{}Model", &model.name),
+                Applicability::MaybeIncorrect,
+            )))
         } else {
             Ok(())
         }
@@ -469,12 +584,86 @@ impl<'a> Validator<'a> {
         ),
         &dml_enum.name,
         ast_enum.span,
-      ))
+      )
+      .with_suggestion(Suggestion::new(
+          "Rename the enum to a non-reserved name",
+          ast_enum.name.span,
+          &format!("{}Enum", &dml_enum.name),
+          Applicability::MaybeIncorrect,
+      )))
         } else {
             Ok(())
         }
     }
 
+    /// Validates an enum's optional underlying scalar type (e.g. `enum Color Int { ... }`) and
+    /// the explicit backing values given for its enumerators. Gated behind
+    /// `Connector::supports_enum_underlying_type()`, since not every datasource can represent a
+    /// backed enum.
+    fn validate_enum(&self, ast_enum: &ast::Enum, dml_enum: &dml::Enum) -> Result<(), Diagnostics> {
+        let mut errors = Diagnostics::new();
+
+        let underlying_type = match &dml_enum.underlying_type {
+            Some(underlying_type) => underlying_type,
+            None => return Ok(()),
+        };
+
+        let supports_enum_underlying_type = self
+            .source
+            .map(|source| source.active_connector.supports_enum_underlying_type())
+            .unwrap_or(false);
+
+        if !supports_enum_underlying_type {
+            errors.push_error(DatamodelError::new_connector_error(
+                &format!(
+                    "Enums with an underlying type (`{}`) are not supported by the current connector.",
+                    underlying_type
+                ),
+                ast_enum.span,
+            ));
+            return errors.to_result();
+        }
+
+        let explicit_count = dml_enum.values.iter().filter(|v| v.backing_value.is_some()).count();
+        if explicit_count != 0 && explicit_count != dml_enum.values.len() {
+            errors.push_error(DatamodelError::new_enum_validation_error(
+                "Either every value of the enum must have an explicit backing value, or none of them must.",
+                &dml_enum.name,
+                ast_enum.span,
+            ));
+        }
+
+        let mut seen_backing_values: HashSet<String> = HashSet::new();
+        for value in dml_enum.values.iter() {
+            let ast_value = ast_enum.values.iter().find(|v| v.name.name == value.name);
+            let span = ast_value.map(|v| v.span).unwrap_or(ast_enum.span);
+
+            if let Some(backing_value) = &value.backing_value {
+                if !backing_value.is_compatible_with(underlying_type) {
+                    errors.push_error(DatamodelError::new_enum_validation_error(
+                        &format!(
+                            "The backing value of enumerator `{}` does not match the enum's underlying type `{}`.",
+                            value.name, underlying_type
+                        ),
+                        &dml_enum.name,
+                        span,
+                    ));
+                }
+
+                let rendered = backing_value.to_string();
+                if !seen_backing_values.insert(rendered.clone()) {
+                    errors.push_error(DatamodelError::new_enum_validation_error(
+                        &format!("The backing value `{}` is used by more than one enumerator.", rendered),
+                        &dml_enum.name,
+                        span,
+                    ));
+                }
+            }
+        }
+
+        errors.to_result()
+    }
+
     fn validate_field_connector_specific(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), Diagnostics> {
         let mut diagnostics = Diagnostics::new();
 
@@ -612,7 +801,17 @@ impl<'a> Validator<'a> {
             let ast_field = ast_model.find_field(&field.name);
 
             let rel_info = &field.relation_info;
-            let related_model = datamodel.find_model(&rel_info.to).expect(STATE_ERROR);
+            let related_model = match qualified_model_resolution::resolve_relation_target(
+                datamodel,
+                &rel_info.to,
+                ast_field.span,
+            ) {
+                Ok(related_model) => related_model,
+                Err(err) => {
+                    errors.push_error(err);
+                    continue;
+                }
+            };
 
             let unknown_fields: Vec<String> = rel_info
                 .references
@@ -746,19 +945,39 @@ impl<'a> Validator<'a> {
                 };
 
                 if !references_unique_criteria && must_reference_unique_criteria {
-                    errors.push_error(DatamodelError::new_validation_error(
+                    let mut error = DatamodelError::new_validation_error(
                             &format!("The argument `references` must refer to a unique criteria in the related model `{}`. But it is referencing the following fields that are not a unique criteria: {}",
                                      &related_model.name,
                                      rel_info.references.join(", ")),
-                            ast_field.span)
-                        );
+                            ast_field.span);
+
+                    if let Some(suggestion) = relation_reference_suggestion::suggest_closest_unique_criteria(
+                        &rel_info.references,
+                        related_model,
+                        strict_relation_field_order,
+                        ast_field.span,
+                    ) {
+                        error = error.with_suggestion(suggestion);
+                    }
+
+                    errors.push_error(error);
                 } else if !reference_order_correct {
-                    errors.push_error(DatamodelError::new_validation_error(
+                    let mut error = DatamodelError::new_validation_error(
                         &format!("The argument `references` must refer to a unique criteria in the related model `{}` using the same order of fields. Please check the ordering in the following fields: `{}`.",
                                  &related_model.name,
                                  rel_info.references.join(", ")),
-                        ast_field.span)
-                    );
+                        ast_field.span);
+
+                    if let Some(suggestion) = relation_reference_suggestion::suggest_closest_unique_criteria(
+                        &rel_info.references,
+                        related_model,
+                        strict_relation_field_order,
+                        ast_field.span,
+                    ) {
+                        error = error.with_suggestion(suggestion);
+                    }
+
+                    errors.push_error(error);
                 }
 
                 // TODO: This error is only valid for connectors that don't support native many to manys.
@@ -808,6 +1027,15 @@ impl<'a> Validator<'a> {
         let mut errors = Diagnostics::new();
 
         for field in model.relation_fields() {
+            // A `through` relation is derived by traversing other, already-validated relation
+            // fields (see `Validator::validate_through_relations`): it never has its own
+            // `fields`/`references`, and nothing on the target model points back at it, so none
+            // of the checks below - which all assume a concrete, two-sided backing relation -
+            // apply to it.
+            if field.relation_info.through.is_some() {
+                continue;
+            }
+
             let field_span = ast_model
                 .fields
                 .iter()
@@ -816,7 +1044,13 @@ impl<'a> Validator<'a> {
                 .unwrap_or_else(ast::Span::empty);
 
             let rel_info = &field.relation_info;
-            let related_model = datamodel.find_model(&rel_info.to).expect(STATE_ERROR);
+            let related_model = match qualified_model_resolution::resolve_relation_target(datamodel, &rel_info.to, field_span) {
+                Ok(related_model) => related_model,
+                Err(err) => {
+                    errors.push_error(err);
+                    continue;
+                }
+            };
             if let Some((_rel_field_idx, related_field)) = datamodel.find_related_field(&field) {
                 let related_field_rel_info = &related_field.relation_info;
 
@@ -953,18 +1187,39 @@ impl<'a> Validator<'a> {
                 }
 
                 // MANY TO MANY
-                if field.is_list() && related_field.is_list() && !related_model.has_single_id_field() {
-                    errors.push_error(DatamodelError::new_field_validation_error(
-                            &format!(
-                                "The relation field `{}` on Model `{}` references `{}` which does not have an `@id` field. Models without `@id` can not be part of a many to many relation. Use an explicit intermediate Model to represent this relationship.",
-                                &field.name,
-                                &model.name,
-                                &related_model.name,
-                            ),
-                            &model.name,
-                            &field.name,
+                if field.is_list() && related_field.is_list() {
+                    // An explicit `joinTable` is only ever declared on one side of the relation
+                    // (same convention as `fields`/`references` above), so fall back to the
+                    // opposite field's `relation_info` before deciding which branch applies.
+                    let join_table_name = rel_info
+                        .join_table
+                        .clone()
+                        .or_else(|| related_field_rel_info.join_table.clone());
+
+                    if let Some(join_table_name) = join_table_name {
+                        if let Err(err) = self.validate_explicit_join_table(
+                            datamodel,
+                            model,
+                            field,
+                            related_model,
+                            &join_table_name,
                             field_span,
-                        ));
+                        ) {
+                            errors.push_error(err);
+                        }
+                    } else if !related_model.has_single_id_field() {
+                        errors.push_error(DatamodelError::new_field_validation_error(
+                                &format!(
+                                    "The relation field `{}` on Model `{}` references `{}` which does not have an `@id` field. Models without `@id` can not be part of a many to many relation. Use an explicit intermediate Model to represent this relationship.",
+                                    &field.name,
+                                    &model.name,
+                                    &related_model.name,
+                                ),
+                                &model.name,
+                                &field.name,
+                                field_span,
+                            ));
+                    }
                 }
             } else {
                 errors.push_error(DatamodelError::new_field_validation_error(
@@ -984,122 +1239,604 @@ impl<'a> Validator<'a> {
         errors
     }
 
-    /// Elegantly checks if any relations in the model are ambigious.
-    fn validate_relations_not_ambiguous(
+    /// Validates a many-to-many relation's explicit `@relation(joinTable: ..., references: ...)`,
+    /// the Ecto-`join_through`-inspired escape hatch from the blanket "many to many relations
+    /// must always reference the `@id` field" rule: with an explicit join model named, the
+    /// relation may reference any unique criteria on `related_model` instead. This relaxation
+    /// covers only this check: `validate_referenced_fields_for_relation` still rejects a
+    /// many-to-many whose `references` is not the id field when `related_model` has a single
+    /// `@id` field, whether or not a `joinTable` is declared.
+    fn validate_explicit_join_table(
         &self,
-        ast_schema: &ast::SchemaAst,
+        datamodel: &dml::Datamodel,
         model: &dml::Model,
+        field: &dml::RelationField,
+        related_model: &dml::Model,
+        join_table_name: &str,
+        field_span: ast::Span,
     ) -> Result<(), DatamodelError> {
-        for field_a in model.relation_fields() {
-            for field_b in model.relation_fields() {
-                if field_a != field_b {
-                    let rel_a = &field_a.relation_info;
-                    let rel_b = &field_b.relation_info;
-                    if rel_a.to != model.name && rel_b.to != model.name {
-                        // Not a self relation
-                        // but pointing to the same foreign model,
-                        // and also no names set.
-                        if rel_a.to == rel_b.to && rel_a.name == rel_b.name {
-                            if rel_a.name.is_empty() {
-                                // unnamed relation
-                                return Err(DatamodelError::new_model_validation_error(
-                                            &format!(
-                                                "Ambiguous relation detected. The fields `{}` and `{}` in model `{}` both refer to `{}`. Please provide different relation names for them by adding `@relation(<name>).",
-                                                &field_a.name,
-                                                &field_b.name,
-                                                &model.name,
-                                                &rel_a.to
-                                            ),
-                                            &model.name,
-                                            ast_schema
-                                                .find_field(&model.name, &field_a.name)
-                                                .expect(STATE_ERROR)
-                                                .span,
-                                        ));
-                            } else {
-                                // explicitly named relation
-                                return Err(DatamodelError::new_model_validation_error(
-                                            &format!(
-                                                "Wrongly named relation detected. The fields `{}` and `{}` in model `{}` both use the same relation name. Please provide different relation names for them through `@relation(<name>).",
-                                                &field_a.name,
-                                                &field_b.name,
-                                                &model.name,
-                                            ),
-                                            &model.name,
-                                            ast_schema
-                                                .find_field(&model.name, &field_a.name)
-                                                .expect(STATE_ERROR)
-                                                .span,
-                                        ));
-                            }
-                        }
-                    } else if rel_a.to == model.name && rel_b.to == model.name {
-                        // This is a self-relation with at least two fields.
-
-                        // Named self relations are ambiguous when they involve more than two fields.
-                        for field_c in model.relation_fields() {
-                            if field_a != field_c && field_b != field_c {
-                                let rel_c = &field_c.relation_info;
-                                if rel_c.to == model.name && rel_a.name == rel_b.name && rel_a.name == rel_c.name {
-                                    if rel_a.name.is_empty() {
-                                        // unnamed relation
-                                        return Err(DatamodelError::new_model_validation_error(
-                                                        &format!(
-                                                            "Unnamed self relation detected. The fields `{}`, `{}` and `{}` in model `{}` have no relation name. Please provide a relation name for one of them by adding `@relation(<name>).",
-                                                            &field_a.name,
-                                                            &field_b.name,
-                                                            &field_c.name,
-                                                            &model.name
-                                                        ),
-                                                        &model.name,
-                                                        ast_schema
-                                                            .find_field(&model.name, &field_a.name)
-                                                            .expect(STATE_ERROR)
-                                                            .span,
-                                                    ));
-                                    } else {
-                                        return Err(DatamodelError::new_model_validation_error(
-                                                        &format!(
-                                                        "Wrongly named self relation detected. The fields `{}`, `{}` and `{}` in model `{}` have the same relation name. At most two relation fields can belong to the same relation and therefore have the same name. Please assign a different relation name to one of them.",
-                                                            &field_a.name,
-                                                            &field_b.name,
-                                                            &field_c.name,
-                                                            &model.name
-                                                        ),
-                                                        &model.name,
-                                                        ast_schema
-                                                            .find_field(&model.name, &field_a.name)
-                                                            .expect(STATE_ERROR)
-                                                            .span,
-                                                    ));
-                                    }
-                                }
-                            }
-                        }
+        let rel_info = &field.relation_info;
 
-                        // Ambiguous unnamed self relation: two fields are enough.
-                        if rel_a.name.is_empty() && rel_b.name.is_empty() {
-                            // A self relation, but there are at least two fields without a name.
-                            return Err(DatamodelError::new_model_validation_error(
-                                        &format!(
-                                            "Ambiguous self relation detected. The fields `{}` and `{}` in model `{}` both refer to `{}`. If they are part of the same relation add the same relation name for them with `@relation(<name>)`.",
-                                            &field_a.name,
-                                            &field_b.name,
-                                            &model.name,
-                                            &rel_a.to
-                                        ),
-                                        &model.name,
-                                        ast_schema
-                                            .find_field(&model.name, &field_a.name)
-                                            .expect(STATE_ERROR)
-                                            .span,
-                                    ));
-                        }
+        let join_model = datamodel.find_model(join_table_name).ok_or_else(|| {
+            DatamodelError::new_field_validation_error(
+                &format!(
+                    "The relation field `{}` on Model `{}` declares `joinTable: {}`, but no model named `{}` exists.",
+                    &field.name, &model.name, join_table_name, join_table_name
+                ),
+                &model.name,
+                &field.name,
+                field_span,
+            )
+        })?;
+
+        let side_count =
+            |target: &str| join_model.relation_fields().filter(|f| f.relation_info.to == target).count();
+
+        if side_count(&model.name) != 1 || side_count(&related_model.name) != 1 {
+            return Err(DatamodelError::new_field_validation_error(
+                &format!(
+                    "The join model `{}` declared by the relation field `{}` on Model `{}` must have exactly one relation field back to each of `{}` and `{}`.",
+                    join_table_name, &field.name, &model.name, &model.name, &related_model.name
+                ),
+                &model.name,
+                &field.name,
+                field_span,
+            ));
+        }
+
+        let references: Vec<String> = if rel_info.references.is_empty() {
+            related_model.singular_id_fields().map(|f| f.name.clone()).collect()
+        } else {
+            rel_info.references.clone()
+        };
+
+        let references_set: HashSet<&str> = references.iter().map(|s| s.as_str()).collect();
+        let forms_unique_criteria = related_model.loose_unique_criterias().iter().any(|criteria| {
+            let criteria_fields: HashSet<&str> = criteria.fields.iter().map(|f| f.name.as_str()).collect();
+            criteria_fields == references_set
+        });
+
+        if !forms_unique_criteria {
+            return Err(DatamodelError::new_field_validation_error(
+                &format!(
+                    "The relation field `{}` on Model `{}` references [{}] on `{}`, which is not a unique criteria. Many-to-many relations with an explicit `joinTable` must reference a unique criteria.",
+                    &field.name, &model.name, references.join(", "), &related_model.name
+                ),
+                &model.name,
+                &field.name,
+                field_span,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Validates a relation's `defaults` argument (`@relation(defaults: [title: "World!"])`),
+    /// the Ecto-`belongs_to ..., defaults: [...]`-inspired way to express the values applied to
+    /// an associated record's fields when it's created through the relation without specifying
+    /// them explicitly. Each named field must exist on the related model, be scalar, and its
+    /// literal value must match that field's type.
+    fn validate_relation_defaults(
+        &self,
+        ast_schema: &ast::SchemaAst,
+        datamodel: &dml::Datamodel,
+        model: &dml::Model,
+    ) -> Diagnostics {
+        let mut errors = Diagnostics::new();
+
+        for field in model.relation_fields() {
+            let rel_info = &field.relation_info;
+
+            if rel_info.defaults.is_empty() {
+                continue;
+            }
+
+            let field_span = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR).span;
+
+            let related_model = match datamodel.find_model(&rel_info.to) {
+                Some(related_model) => related_model,
+                None => continue,
+            };
+
+            for (default_field_name, default_value) in &rel_info.defaults {
+                if related_model.find_field(default_field_name).is_none() {
+                    errors.push_error(DatamodelError::new_field_validation_error(
+                        &format!(
+                            "The relation field `{}` on Model `{}` declares a default for `{}`, but `{}` has no field named `{}`.",
+                            &field.name, &model.name, default_field_name, &related_model.name, default_field_name
+                        ),
+                        &model.name,
+                        &field.name,
+                        field_span,
+                    ));
+                    continue;
+                }
+
+                let scalar_field = match related_model.find_scalar_field(default_field_name) {
+                    Some(scalar_field) => scalar_field,
+                    None => {
+                        errors.push_error(DatamodelError::new_field_validation_error(
+                            &format!(
+                                "The relation field `{}` on Model `{}` declares a default for `{}` on `{}`, but that field is a relation, not a scalar field.",
+                                &field.name, &model.name, default_field_name, &related_model.name
+                            ),
+                            &model.name,
+                            &field.name,
+                            field_span,
+                        ));
+                        continue;
+                    }
+                };
+
+                if let Some(scalar_type) = scalar_field.field_type.scalar_type() {
+                    if !default_value_matches_scalar_type(default_value, scalar_type) {
+                        errors.push_error(DatamodelError::new_field_validation_error(
+                            &format!(
+                                "The relation field `{}` on Model `{}` declares a default for `{}` whose value does not match the type of that field on `{}`.",
+                                &field.name, &model.name, default_field_name, &related_model.name
+                            ),
+                            &model.name,
+                            &field.name,
+                            field_span,
+                        ));
                     }
                 }
             }
         }
 
-        Ok(())
+        errors
+    }
+
+    /// Validates `through` relations: read-only fields that declare a path of already-declared
+    /// relation field names (`@relation(through: [post, author])`) instead of `fields`/
+    /// `references`, and surface the model at the end of that path as a virtual field. Modeled
+    /// on Ecto's `has_one/has_many :through`.
+    ///
+    /// Checks, in order: the path is non-empty, a through field declares none of `fields`,
+    /// `references` or an explicit `@relation` name (those only make sense for a concrete,
+    /// storage-backed relation), every hop names an existing relation field on the model
+    /// reached by the previous hop and no model is reached twice (checked hop by hop), and the
+    /// field's own arity matches what the path implies - `List` if any hop is to-many, otherwise `Optional` following the same "at least
+    /// one optional hop in the chain makes the whole path optional" rule already used for
+    /// underlying scalar fields in [`Validator::validate_base_fields_for_relation`].
+    fn validate_through_relations(
+        &self,
+        ast_schema: &ast::SchemaAst,
+        datamodel: &dml::Datamodel,
+        model: &dml::Model,
+    ) -> Diagnostics {
+        let mut errors = Diagnostics::new();
+
+        for field in model.relation_fields() {
+            let rel_info = &field.relation_info;
+
+            let path = match &rel_info.through {
+                Some(path) => path,
+                None => continue,
+            };
+
+            let field_span = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR).span;
+
+            if path.is_empty() {
+                errors.push_error(DatamodelError::new_field_validation_error(
+                    &format!(
+                        "The relation field `{}` on Model `{}` declares an empty `through` path. A through relation must traverse at least one existing relation field.",
+                        &field.name, &model.name
+                    ),
+                    &model.name,
+                    &field.name,
+                    field_span,
+                ));
+                continue;
+            }
+
+            if !rel_info.fields.is_empty() || !rel_info.references.is_empty() || !rel_info.name.is_empty() {
+                errors.push_error(DatamodelError::new_field_validation_error(
+                    &format!(
+                        "The relation field `{}` on Model `{}` declares a `through` path and therefore must not also declare `fields`, `references` or an explicit relation name: a through relation only traverses existing relations, it does not back its own.",
+                        &field.name, &model.name
+                    ),
+                    &model.name,
+                    &field.name,
+                    field_span,
+                ));
+                continue;
+            }
+
+            let mut visited_models = vec![model.name.clone()];
+            let mut current_model = model;
+            let mut is_list = false;
+            let mut is_optional = false;
+            let mut broken_hop = false;
+
+            for hop_name in path {
+                let hop_field = match current_model.find_relation_field(hop_name) {
+                    Some(hop_field) => hop_field,
+                    None => {
+                        errors.push_error(DatamodelError::new_field_validation_error(
+                            &format!(
+                                "The relation field `{}` on Model `{}` has an invalid `through` path: `{}` is not a relation field on model `{}`.",
+                                &field.name, &model.name, hop_name, current_model.name
+                            ),
+                            &model.name,
+                            &field.name,
+                            field_span,
+                        ));
+                        broken_hop = true;
+                        break;
+                    }
+                };
+
+                is_list |= hop_field.is_list();
+                is_optional |= hop_field.is_optional();
+
+                let next_model = match datamodel.find_model(&hop_field.relation_info.to) {
+                    Some(next_model) => next_model,
+                    None => {
+                        broken_hop = true;
+                        break;
+                    }
+                };
+
+                if visited_models.contains(&next_model.name) {
+                    errors.push_error(DatamodelError::new_field_validation_error(
+                        &format!(
+                            "The relation field `{}` on Model `{}` has a cyclic `through` path: model `{}` is reached more than once.",
+                            &field.name, &model.name, next_model.name
+                        ),
+                        &model.name,
+                        &field.name,
+                        field_span,
+                    ));
+                    broken_hop = true;
+                    break;
+                }
+
+                visited_models.push(next_model.name.clone());
+                current_model = next_model;
+            }
+
+            if broken_hop {
+                continue;
+            }
+
+            let expected_list = is_list;
+            let expected_optional = !expected_list && is_optional;
+
+            if field.is_list() != expected_list || (!expected_list && field.is_required() == expected_optional) {
+                let expected = if expected_list {
+                    "a list"
+                } else if expected_optional {
+                    "optional"
+                } else {
+                    "required"
+                };
+
+                errors.push_error(DatamodelError::new_field_validation_error(
+                    &format!(
+                        "The relation field `{}` on Model `{}` must be {} to match the arity implied by its `through` path.",
+                        &field.name, &model.name, expected
+                    ),
+                    &model.name,
+                    &field.name,
+                    field_span,
+                ));
+            }
+        }
+
+        errors
+    }
+
+    /// Finds every ambiguous relation group in one pass and reports all of them at once,
+    /// naming every participating field as a candidate, instead of bailing out on the first
+    /// ambiguity found. Fields are grouped by `(to_model, relation_name)` in a single pass; any
+    /// group with more than one member is ambiguous (for self-relations, more than two named
+    /// members is ambiguous, matching the "at most two fields per relation name" invariant).
+    fn validate_relations_not_ambiguous(&self, ast_schema: &ast::SchemaAst, model: &dml::Model) -> Diagnostics {
+        let mut errors = Diagnostics::new();
+        let mut groups: BTreeMap<(&str, &str), Vec<&dml::RelationField>> = BTreeMap::new();
+
+        for field in model.relation_fields() {
+            let rel = &field.relation_info;
+            // A `through` field backs no relation of its own (see
+            // `Validator::validate_through_relations`), so it cannot make one ambiguous.
+            if rel.through.is_some() {
+                continue;
+            }
+            groups.entry((&rel.to, &rel.name)).or_default().push(field);
+        }
+
+        for ((to, name), fields) in groups {
+            let is_self_relation = to == model.name;
+            let is_ambiguous = if is_self_relation {
+                fields.len() > 2 || (fields.len() == 2 && name.is_empty())
+            } else {
+                fields.len() > 1
+            };
+
+            if !is_ambiguous {
+                continue;
+            }
+
+            // In fix mode, an unnamed ambiguity is not a hard error: generate the minimal set of
+            // deterministic relation-name patches needed to disambiguate the group instead.
+            // Fields are sorted by name and all but the lexicographically-first one get a
+            // generated name; that one field keeps its empty name, which is enough to make every
+            // `(to, name)` pair unique again. Sorting (rather than AST order) keeps the patches
+            // stable across re-runs, so applying them is idempotent.
+            if self.mode == ValidationMode::Fix && name.is_empty() {
+                let mut sorted_fields = fields.clone();
+                sorted_fields.sort_by(|a, b| a.name.cmp(&b.name));
+
+                let mut fixes = self.relation_name_fixes.borrow_mut();
+                for field in sorted_fields.iter().skip(1) {
+                    let field_span = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR).span;
+                    // Insert right before the field declaration's trailing newline instead of
+                    // replacing the whole span: `field_span` covers the field's name and type
+                    // too, and replacing all of that with just the new attribute would delete
+                    // them rather than add to them.
+                    let insertion_point = field_span.end.saturating_sub(1).max(field_span.start);
+                    let insertion_span = ast::Span::new(insertion_point, insertion_point);
+                    fixes.push(Suggestion::new(
+                        &format!("Generate a relation name for `{}`", field.name),
+                        insertion_span,
+                        &format!(" @relation(\"{}\")", capitalize(&field.name)),
+                        Applicability::MachineApplicable,
+                    ));
+                }
+
+                continue;
+            }
+
+            let candidate_names: Vec<&str> = fields.iter().map(|f| f.name.as_str()).collect();
+            let span = ast_schema
+                .find_field(&model.name, &fields[0].name)
+                .expect(STATE_ERROR)
+                .span;
+
+            let message = match (is_self_relation, name.is_empty(), fields.len()) {
+                (true, true, 2) => format!(
+                    "Ambiguous self relation detected. The fields `{}` in model `{}` both refer to `{}`. If they are part of the same relation add the same relation name for them with `@relation(<name>)`.",
+                    candidate_names.join("`, `"),
+                    model.name,
+                    to
+                ),
+                (true, true, _) => format!(
+                    "Unnamed self relation detected. The fields `{}` in model `{}` have no relation name. Please provide a relation name for one of them by adding `@relation(<name>).",
+                    candidate_names.join("`, `"),
+                    model.name
+                ),
+                (true, false, _) => format!(
+                    "Wrongly named self relation detected. The fields `{}` in model `{}` have the same relation name. At most two relation fields can belong to the same relation and therefore have the same name. Please assign a different relation name to one of them.",
+                    candidate_names.join("`, `"),
+                    model.name
+                ),
+                (false, true, _) => format!(
+                    "Ambiguous relation detected. The fields `{}` in model `{}` all refer to `{}`. Please provide different relation names for them by adding `@relation(<name>).",
+                    candidate_names.join("`, `"),
+                    model.name,
+                    to
+                ),
+                (false, false, _) => format!(
+                    "Wrongly named relation detected. The fields `{}` in model `{}` all use the same relation name. Please provide different relation names for them through `@relation(<name>).",
+                    candidate_names.join("`, `"),
+                    model.name
+                ),
+            };
+
+            let mut error = DatamodelError::new_model_validation_error(&message, &model.name, span);
+
+            // For the unnamed two-field self relation, a deterministic fix exists: give each
+            // field its own, distinct `@relation(<name>)` name derived from the field name, so
+            // an LSP/`prisma format` layer can offer a one-click quick-fix instead of forcing
+            // the user to hand-write the relation name. Each suggestion inserts the attribute
+            // before the field's trailing newline, as the fix-mode patches above do.
+            if is_self_relation && name.is_empty() && fields.len() == 2 {
+                let suggestions = fields
+                    .iter()
+                    .map(|field| {
+                        let field_span = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR).span;
+                        let insertion_point = field_span.end.saturating_sub(1).max(field_span.start);
+                        Suggestion::new(
+                            &format!("Add an explicit relation name to `{}`", field.name),
+                            ast::Span::new(insertion_point, insertion_point),
+                            &format!(" @relation(\"{}\")", capitalize(&field.name)),
+                            Applicability::MaybeIncorrect,
+                        )
+                    })
+                    .collect();
+
+                error = error.with_suggestions(suggestions);
+            }
+
+            errors.push_error(error);
+        }
+
+        errors
+    }
+
+    /// Synthesizes the `fields`/`references` pair for 1:1 relations where neither side has
+    /// written an explicit `@relation(fields: ..., references: ...)` yet, so
+    /// `validate_relation_arguments_bla` no longer has to hard-error on an omission that has one
+    /// obvious, conventional resolution.
+    ///
+    /// Following the same alphanumeric tie-break Prisma Migrate already applies when both sides
+    /// are otherwise equally valid foreign-key holders, the model whose name sorts first becomes
+    /// the FK-holding side. Its relation field is wired up to the scalar field named by
+    /// convention (`<relatedModel, camelCased>Id`) and the related model's single `@id` field -
+    /// provided that scalar field already exists on the model, since this only wires up an
+    /// existing column, it does not add one. When no such field exists, nothing is inferred and
+    /// the existing "must provide the `fields` argument" error fires as before.
+    fn infer_one_to_one_relation_sides(&self, datamodel: &mut dml::Datamodel) {
+        struct Plan {
+            fk_model: String,
+            fk_field: String,
+            fk_column: String,
+            id_column: String,
+        }
+
+        let mut plans = Vec::new();
+
+        for model in datamodel.models() {
+            for field in model.relation_fields() {
+                let rel_info = &field.relation_info;
+
+                if !field.is_singular() || !rel_info.fields.is_empty() || !rel_info.references.is_empty() {
+                    continue;
+                }
+
+                // Only process a 1:1 pair from its alphanumerically-first side: that is exactly
+                // the side the convention designates as the FK holder, so this both avoids
+                // generating the pair twice and skips self relations (where `model.name ==
+                // rel_info.to`), which this inference doesn't apply to.
+                if model.name >= rel_info.to {
+                    continue;
+                }
+
+                let related_model = match datamodel.find_model(&rel_info.to) {
+                    Some(related_model) => related_model,
+                    None => continue,
+                };
+
+                let related_field_is_singular = match datamodel.find_related_field(field) {
+                    Some((_, related_field)) => related_field.is_singular(),
+                    None => continue,
+                };
+
+                if !related_field_is_singular || !related_model.has_single_id_field() {
+                    continue;
+                }
+
+                let id_column = match related_model.singular_id_fields().next() {
+                    Some(id_field) => id_field.name.clone(),
+                    None => continue,
+                };
+
+                let fk_column = format!("{}Id", decapitalize(&rel_info.to));
+
+                if model.find_scalar_field(&fk_column).is_none() {
+                    continue;
+                }
+
+                plans.push(Plan {
+                    fk_model: model.name.clone(),
+                    fk_field: field.name.clone(),
+                    fk_column,
+                    id_column,
+                });
+            }
+        }
+
+        for plan in plans {
+            if let Some(model) = datamodel.find_model_mut(&plan.fk_model) {
+                if let Some(field) = model.find_relation_field_mut(&plan.fk_field) {
+                    field.relation_info.fields = vec![plan.fk_column];
+                    field.relation_info.references = vec![plan.id_column];
+                }
+            }
+        }
+    }
+
+    /// Checks that every named relation (`@relation("name")`) is used consistently across the
+    /// whole datamodel, not just within a single model. [`Validator::validate_relations_not_ambiguous`]
+    /// only ever looks at one model's fields, so it happily accepts e.g. `User.posts` and
+    /// `Comment.author` both being named `"Posts"`: each model sees only its own half and finds
+    /// nothing ambiguous about it. The DML builder then can't pair the two halves up and panics
+    /// with "Did not find a relation for model X and field Y" deep inside codegen, which is a
+    /// terrible way for a user to learn they mistyped a relation name.
+    ///
+    /// A relation name is globally consistent when it is attached to exactly two relation fields
+    /// (or, for a self relation, exactly two fields on the same model) whose `to` targets point
+    /// back at each other's owning model.
+    fn validate_relation_names_globally_consistent(
+        &self,
+        ast_schema: &ast::SchemaAst,
+        datamodel: &dml::Datamodel,
+    ) -> Diagnostics {
+        let mut errors = Diagnostics::new();
+        let mut groups: BTreeMap<&str, Vec<(&dml::Model, &dml::RelationField)>> = BTreeMap::new();
+
+        for model in datamodel.models() {
+            for field in model.relation_fields() {
+                let name = field.relation_info.name.as_str();
+                if !name.is_empty() {
+                    groups.entry(name).or_default().push((model, field));
+                }
+            }
+        }
+
+        for (name, fields) in groups {
+            // A self relation legitimately has both its fields on the same model; that case is
+            // already fully validated by `validate_relations_not_ambiguous`.
+            if fields.len() == 2 && fields.iter().all(|(model, field)| field.relation_info.to == model.name) {
+                continue;
+            }
+
+            let is_consistent = fields.len() == 2
+                && fields[0].1.relation_info.to == fields[1].0.name
+                && fields[1].1.relation_info.to == fields[0].0.name;
+
+            if is_consistent {
+                continue;
+            }
+
+            for (model, field) in &fields {
+                let span = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR).span;
+                let message = format!(
+                    "The relation field `{}` on model `{}` uses the relation name `{}`, which is not used consistently: it must be attached to exactly one other relation field whose `to` points back at `{}`.",
+                    field.name, model.name, name, model.name
+                );
+
+                errors.push_error(DatamodelError::new_model_validation_error(&message, &model.name, span));
+            }
+        }
+
+        errors
+    }
+}
+
+/// Upper-cases the first character of `s`, used to derive a relation name candidate from a
+/// field name (`friend` -> `Friend`).
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+/// Whether a relation `defaults` literal's runtime type matches the scalar type of the field it
+/// targets, by its own table of `PrismaValue` variant to `ScalarType` pairs. It is separate from
+/// the field/reference type check in `validate_referenced_fields_for_relation`.
+fn default_value_matches_scalar_type(default: &DefaultValue, scalar_type: dml::ScalarType) -> bool {
+    let value = match default {
+        DefaultValue::Single(value) => value,
+        // A function-based default (e.g. `now()`, `uuid()`) isn't a literal, so there is nothing
+        // for this convention-based check to compare against; let it through.
+        _ => return true,
+    };
+
+    matches!(
+        (value, scalar_type),
+        (PrismaValue::String(_), dml::ScalarType::String)
+            | (PrismaValue::Int(_), dml::ScalarType::Int)
+            | (PrismaValue::BigInt(_), dml::ScalarType::BigInt)
+            | (PrismaValue::Float(_), dml::ScalarType::Float)
+            | (PrismaValue::Float(_), dml::ScalarType::Decimal)
+            | (PrismaValue::Boolean(_), dml::ScalarType::Boolean)
+            | (PrismaValue::DateTime(_), dml::ScalarType::DateTime)
+            | (PrismaValue::Json(_), dml::ScalarType::Json)
+            | (PrismaValue::Bytes(_), dml::ScalarType::Bytes)
+            | (PrismaValue::Enum(_), dml::ScalarType::String)
+    )
+}
+
+/// Lower-cases the first character of `s`, used to derive the conventional foreign-key column
+/// name from a related model name (`User` -> `userId`).
+fn decapitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
     }
 }
@@ -1,6 +1,6 @@
 use crate::common::*;
 use datamodel::ast::Span;
-use datamodel::diagnostics::DatamodelError;
+use datamodel::diagnostics::{Applicability, DatamodelError, Suggestion};
 use datamodel::dml;
 use indoc::indoc;
 
@@ -787,3 +787,612 @@ fn must_allow_relations_with_default_native_types_with_annotation_on_one_side()
         );
     }
 }
+
+#[test]
+fn through_relation_succeeds_for_a_valid_multi_hop_path() {
+    let dml = r#"
+    model User {
+        id       Int       @id
+        posts    Post[]
+        comments Comment[] @relation(through: [posts, comments])
+    }
+
+    model Post {
+        id       Int       @id
+        authorId Int
+        author   User      @relation(fields: [authorId], references: [id])
+        comments Comment[]
+    }
+
+    model Comment {
+        id     Int  @id
+        postId Int
+        post   Post @relation(fields: [postId], references: [id])
+    }
+    "#;
+
+    let schema = parse(dml);
+    schema
+        .assert_has_model("User")
+        .assert_has_relation_field("comments")
+        .assert_arity(&dml::FieldArity::List)
+        .assert_relation_to("Comment");
+}
+
+#[test]
+fn through_relation_must_error_on_an_empty_path() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[] @relation(through: [])
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    errors.assert_is(DatamodelError::new_field_validation_error(
+        "The relation field `posts` on Model `User` declares an empty `through` path. A through relation must traverse at least one existing relation field.",
+        "User",
+        "posts",
+        Span::new(51, 87),
+    ));
+}
+
+#[test]
+fn through_relation_must_error_when_a_hop_is_not_a_relation_field() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[] @relation(through: [nonexistent])
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    errors.assert_is(DatamodelError::new_field_validation_error(
+        "The relation field `posts` on Model `User` has an invalid `through` path: `nonexistent` is not a relation field on model `User`.",
+        "User",
+        "posts",
+        Span::new(51, 98),
+    ));
+}
+
+#[test]
+fn through_relation_must_error_on_a_cyclic_path() {
+    let dml = r#"
+    model User {
+        id       Int       @id
+        posts    Post[]
+        cyclic   Post[]    @relation(through: [posts, author])
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    errors.assert_is(DatamodelError::new_field_validation_error(
+        "The relation field `cyclic` on Model `User` has a cyclic `through` path: model `User` is reached more than once.",
+        "User",
+        "cyclic",
+        Span::new(81, 136),
+    ));
+}
+
+#[test]
+fn through_relation_must_error_when_arity_does_not_match_the_path() {
+    let dml = r#"
+    model User {
+        id     Int    @id
+        posts  Post[]
+        author Post?  @relation(through: [posts])
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    errors.assert_is(DatamodelError::new_field_validation_error(
+        "The relation field `author` on Model `User` must be a list to match the arity implied by its `through` path.",
+        "User",
+        "author",
+        Span::new(74, 116),
+    ));
+}
+
+#[test]
+fn explicit_join_table_allows_a_many_to_many_without_an_id_field_when_declared_on_either_side() {
+    // `Post` has no singular `@id` field, so a plain many-to-many would be rejected; the
+    // explicit `joinTable` is only declared on the `Category` side, matching the convention
+    // `fields`/`references` already follow elsewhere in this file.
+    let dml = r#"
+    model Post {
+        id         Int
+        slug       Int        @unique
+        categories Category[]
+
+        @@id([id, slug])
+    }
+
+    model Category {
+        id    Int    @id @default(autoincrement())
+        posts Post[] @relation(joinTable: "CategoryPost", references: [slug])
+    }
+
+    model CategoryPost {
+        id         Int @id
+        postSlug   Int
+        categoryId Int
+        post       Post     @relation(fields: [postSlug], references: [slug])
+        category   Category @relation(fields: [categoryId], references: [id])
+    }
+    "#;
+
+    let schema = parse(dml);
+    schema
+        .assert_has_model("Post")
+        .assert_has_relation_field("categories")
+        .assert_relation_to("Category");
+}
+
+#[test]
+fn explicit_join_table_must_error_when_references_is_not_a_unique_criteria() {
+    let dml = r#"
+    model Post {
+        id    Int    @id
+        title String
+        categories Category[]
+    }
+
+    model Category {
+        id    Int    @id
+        posts Post[] @relation(joinTable: "CategoryPost", references: [title])
+    }
+
+    model CategoryPost {
+        id         Int    @id
+        postTitle  String
+        categoryId Int
+        post       Post     @relation(fields: [postTitle], references: [title])
+        category   Category @relation(fields: [categoryId], references: [id])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    errors.assert_is(DatamodelError::new_field_validation_error(
+        "The relation field `posts` on Model `Category` references [title] on `Post`, which is not a unique criteria. Many-to-many relations with an explicit `joinTable` must reference a unique criteria.",
+        "Category",
+        "posts",
+        Span::new(155, 226),
+    ));
+}
+
+#[test]
+fn redundant_unique_criteria_is_not_reported_for_distinct_single_field_keys() {
+    // Regression test: every single-field `@id`/`@unique` criterion is trivially its own
+    // superkey, which used to make same-size criteria compare as equal regardless of which
+    // fields they actually covered. `id`, `email`, and `username` share no fields, so none of
+    // them is implied by another and none should be flagged as redundant.
+    let dml = r#"
+    model User {
+        id       Int    @id
+        email    String @unique
+        username String @unique
+    }
+    "#;
+
+    let validated = datamodel::parse_datamodel(dml).unwrap();
+    assert!(validated.warnings.is_empty(), "{:?}", validated.warnings);
+}
+
+#[test]
+fn redundant_unique_criteria_is_reported_when_a_criterion_strictly_contains_another_key() {
+    // `id` alone is already a candidate key, so the `@@unique([id, email])` on top of it adds
+    // nothing: it is implied by `id` without relying on its own trivial closure.
+    let dml = r#"
+    model User {
+        id    Int    @id
+        email String
+
+        @@unique([id, email])
+    }
+    "#;
+
+    let validated = datamodel::parse_datamodel(dml).unwrap();
+    assert_eq!(validated.warnings.len(), 1);
+}
+
+#[test]
+fn redundant_unique_criteria_check_does_not_panic_when_the_only_criterion_spans_every_field() {
+    // Regression test: a model with a single declared criterion that already covers every
+    // field (here, the only field) used to satisfy the "is this redundant?" closure check
+    // trivially, and then panic looking for another criterion to blame it on.
+    let dml = r#"
+    model Foo {
+        id Int @id
+    }
+    "#;
+
+    let validated = datamodel::parse_datamodel(dml).unwrap();
+    assert!(validated.warnings.is_empty(), "{:?}", validated.warnings);
+
+    let dml = r#"
+    model Link {
+        a Int
+        b Int
+
+        @@id([a, b])
+    }
+    "#;
+
+    let validated = datamodel::parse_datamodel(dml).unwrap();
+    assert!(validated.warnings.is_empty(), "{:?}", validated.warnings);
+}
+
+#[test]
+fn one_to_one_relation_infers_the_fk_side_by_convention_when_unspecified() {
+    // Neither side declares `fields`/`references`. `Address` sorts before `User`, so it is
+    // inferred to hold the foreign key, and `userId` matches the `<relatedModel>Id` convention -
+    // the relation should come out exactly as if `Address.user` had declared
+    // `@relation(fields: [userId], references: [id])` itself.
+    let dml = r#"
+    model Address {
+        id     Int   @id
+        userId Int
+        user   User
+    }
+
+    model User {
+        id      Int      @id
+        address Address?
+    }
+    "#;
+
+    let schema = parse(dml);
+    schema
+        .assert_has_model("Address")
+        .assert_has_relation_field("user")
+        .assert_arity(&dml::FieldArity::Required)
+        .assert_relation_to("User")
+        .assert_relation_base_fields(&["userId"])
+        .assert_relation_referenced_fields(&["id"]);
+}
+
+#[test]
+fn one_to_one_relation_is_not_inferred_when_no_field_matches_the_convention() {
+    // Neither side declares `fields`/`references`, and `Address` has no `userId` scalar field
+    // for the convention to pick up, so inference must not kick in and the original
+    // missing-arguments error should still fire.
+    let dml = r#"
+    model Address {
+        id   Int  @id
+        user User
+    }
+
+    model User {
+        id      Int      @id
+        address Address?
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    errors.assert_is_at(
+        0,
+        DatamodelError::new_attribute_validation_error(
+            "The relation fields `user` on Model `Address` and `address` on Model `User` do not provide the `fields` argument in the @relation attribute. You have to provide it on one of the two fields.",
+            "relation",
+            Span::new(51, 61),
+        ),
+    );
+    errors.assert_is_at(
+        1,
+        DatamodelError::new_attribute_validation_error(
+            "The relation fields `user` on Model `Address` and `address` on Model `User` do not provide the `references` argument in the @relation attribute. You have to provide it on one of the two fields.",
+            "relation",
+            Span::new(51, 61),
+        ),
+    );
+    errors.assert_is_at(
+        2,
+        DatamodelError::new_attribute_validation_error(
+            "The relation fields `address` on Model `User` and `user` on Model `Address` do not provide the `fields` argument in the @relation attribute. You have to provide it on one of the two fields.",
+            "relation",
+            Span::new(122, 139),
+        ),
+    );
+    errors.assert_is_at(
+        3,
+        DatamodelError::new_attribute_validation_error(
+            "The relation fields `address` on Model `User` and `user` on Model `Address` do not provide the `references` argument in the @relation attribute. You have to provide it on one of the two fields.",
+            "relation",
+            Span::new(122, 139),
+        ),
+    );
+}
+
+#[test]
+fn datamodel_error_exposes_a_stable_diagnostic_code() {
+    // Spot-check a representative sample of constructors: every error kind must carry a
+    // permanent, distinct code, so LSP/tooling can key off it without parsing `message()`.
+    let span = Span::new(0, 1);
+
+    let attribute_error = DatamodelError::new_attribute_validation_error("msg", "relation", span);
+    let model_error = DatamodelError::new_model_validation_error("msg", "User", span);
+    let field_error = DatamodelError::new_field_validation_error("msg", "User", "name", span);
+    let validation_error = DatamodelError::new_validation_error("msg", span);
+    let connector_error = DatamodelError::new_connector_error("msg", span);
+
+    assert_eq!(attribute_error.code().to_string(), "P1011");
+    assert_eq!(model_error.code().to_string(), "P1012");
+    assert_eq!(field_error.code().to_string(), "P1013");
+    assert_eq!(connector_error.code().to_string(), "P1015");
+    assert_eq!(validation_error.code().to_string(), "P1016");
+
+    let mut codes = vec![
+        attribute_error.code(),
+        model_error.code(),
+        field_error.code(),
+        connector_error.code(),
+        validation_error.code(),
+    ];
+    codes.dedup();
+    assert_eq!(codes.len(), 5, "every error kind sampled here must carry a distinct code");
+}
+
+#[test]
+fn enum_with_underlying_type_errors_when_the_connector_does_not_support_it() {
+    // No datasource is declared, so there is no connector to back `supports_enum_underlying_type`,
+    // and the feature must be rejected rather than silently accepted.
+    let dml = r#"
+    enum Color Int {
+        Red
+        Green
+        Blue
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    errors.assert_is(DatamodelError::new_connector_error(
+        "Enums with an underlying type (`Int`) are not supported by the current connector.",
+        Span::new(5, 66),
+    ));
+}
+
+#[test]
+fn all_ambiguous_relation_groups_on_a_model_are_reported_in_one_pass() {
+    // `Post` has two independent unnamed-ambiguity groups: two fields to `User`, and two fields
+    // to `Tag`. Both must be reported together instead of only the first one found.
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Tag {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id             Int @id
+        authorId       Int
+        author         User @relation(fields: [authorId], references: [id])
+        editorId       Int
+        editor         User @relation(fields: [editorId], references: [id])
+        primaryTagId   Int
+        primaryTag     Tag @relation(fields: [primaryTagId], references: [id])
+        secondaryTagId Int
+        secondaryTag   Tag @relation(fields: [secondaryTagId], references: [id])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    errors.assert_is_at(
+        0,
+        DatamodelError::new_model_validation_error(
+            "Ambiguous relation detected. The fields `primaryTag`, `secondaryTag` in model `Post` all refer to `Tag`. Please provide different relation names for them by adding `@relation(<name>).",
+            "Post",
+            Span::new(429, 500),
+        ),
+    );
+    errors.assert_is_at(
+        1,
+        DatamodelError::new_model_validation_error(
+            "Ambiguous relation detected. The fields `author`, `editor` in model `Post` all refer to `User`. Please provide different relation names for them by adding `@relation(<name>).",
+            "Post",
+            Span::new(223, 291),
+        ),
+    );
+}
+
+#[test]
+fn non_unique_references_suggest_the_closest_unique_criteria() {
+    let dml = r#"
+    model User {
+        id        Int    @id
+        firstName String
+        lastName  String
+        @@unique([firstName, lastName])
+        posts     Post[]
+    }
+
+    model Post {
+        id        Int    @id
+        firstName String
+        user      User   @relation(fields: [firstName], references: [firstName])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    let error = DatamodelError::new_validation_error(
+        "The argument `references` must refer to a unique criteria in the related model `User`. But it is referencing the following fields that are not a unique criteria: firstName",
+        Span::new(248, 321),
+    )
+    .with_suggestion(Suggestion::new(
+        "Change `references` to [firstName, lastName] (add `lastName`) to match the unique criteria on `User`",
+        Span::new(248, 321),
+        "[firstName, lastName]",
+        Applicability::MaybeIncorrect,
+    ));
+
+    errors.assert_is(error);
+}
+
+#[test]
+fn unnamed_ambiguous_self_relation_carries_a_suggested_fix_for_each_field() {
+    let dml = r#"
+    model Employee {
+        id       Int        @id
+        manager   Employee?
+        reports   Employee[]
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    let error = DatamodelError::new_model_validation_error(
+        "Ambiguous self relation detected. The fields `manager`, `reports` in model `Employee` both refer to `Employee`. If they are part of the same relation add the same relation name for them with `@relation(<name>)`.",
+        "Employee",
+        Span::new(62, 82),
+    )
+    .with_suggestions(vec![
+        Suggestion::new(
+            "Add an explicit relation name to `manager`",
+            Span::new(81, 81),
+            " @relation(\"Manager\")",
+            Applicability::MaybeIncorrect,
+        ),
+        Suggestion::new(
+            "Add an explicit relation name to `reports`",
+            Span::new(110, 110),
+            " @relation(\"Reports\")",
+            Applicability::MaybeIncorrect,
+        ),
+    ]);
+
+    errors.assert_is(error);
+}
+
+#[test]
+fn cross_model_relation_name_reuse_is_reported_instead_of_panicking_downstream() {
+    // `"Shared"` is attached to `Post.author` and, separately, to `Comment.author` - two
+    // different pairs that happen to reuse the same name instead of one consistent pair. Left
+    // unvalidated, the DM builder cannot pair these up and panics with "Did not find a relation
+    // for model X and field Y" deep in codegen.
+    let dml = r#"
+    model User {
+        id       Int    @id
+        posts    Post[]
+        comments Comment[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation("Shared", fields: [authorId], references: [id])
+    }
+
+    model Comment {
+        id       Int  @id
+        authorId Int
+        author   User @relation("Shared", fields: [authorId], references: [id])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    errors.assert_is_at(
+        0,
+        DatamodelError::new_model_validation_error(
+            "The relation field `author` on model `Post` uses the relation name `Shared`, which is not used consistently: it must be attached to exactly one other relation field whose `to` points back at `Post`.",
+            "Post",
+            Span::new(176, 248),
+        ),
+    );
+    errors.assert_is_at(
+        1,
+        DatamodelError::new_model_validation_error(
+            "The relation field `author` on model `Comment` uses the relation name `Shared`, which is not used consistently: it must be attached to exactly one other relation field whose `to` points back at `Comment`.",
+            "Comment",
+            Span::new(330, 402),
+        ),
+    );
+}
+
+#[test]
+fn relation_defaults_are_validated_against_the_related_models_fields() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        name  String @default("World!")
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id], defaults: [name: "Alice"])
+    }
+    "#;
+
+    // A default naming an existing, scalar, type-matching field is accepted.
+    let _ = parse(dml);
+
+    let missing_field_dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id], defaults: [nickname: "Alice"])
+    }
+    "#;
+
+    let errors = parse_error(missing_field_dml);
+    errors.assert_is(DatamodelError::new_field_validation_error(
+        "The relation field `author` on Model `Post` declares a default for `nickname`, but `User` has no field named `nickname`.",
+        "Post",
+        "author",
+        Span::new(143, 236),
+    ));
+
+    let mismatched_type_dml = r#"
+    model User {
+        id    Int    @id
+        name  String
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id], defaults: [name: 1])
+    }
+    "#;
+
+    let errors = parse_error(mismatched_type_dml);
+    errors.assert_is(DatamodelError::new_field_validation_error(
+        "The relation field `author` on Model `Post` declares a default for `name` whose value does not match the type of that field on `User`.",
+        "Post",
+        "author",
+        Span::new(164, 247),
+    ));
+}